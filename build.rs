@@ -0,0 +1,6 @@
+fn main() {
+    cfg_aliases::cfg_aliases! {
+        apple: { any(target_os = "ios", target_os = "macos") },
+        free_unix: { all(unix, not(apple), not(target_os = "android"), not(target_os = "redox")) },
+    }
+}
@@ -0,0 +1,28 @@
+use core::ptr;
+
+/// Raw window handle for Android NDK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct AndroidHandle {
+    /// A pointer to an `ANativeWindow`.
+    pub a_native_window: *mut core::ffi::c_void,
+}
+
+impl AndroidHandle {
+    pub fn empty() -> AndroidHandle {
+        AndroidHandle {
+            a_native_window: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw display handle for Android NDK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct AndroidDisplayHandle {}
+
+impl AndroidDisplayHandle {
+    pub fn empty() -> AndroidDisplayHandle {
+        AndroidDisplayHandle {}
+    }
+}
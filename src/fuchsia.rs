@@ -0,0 +1,13 @@
+/// Raw window handle for Fuchsia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct FuchsiaHandle {
+    /// A Zircon `zx_handle_t` referring to the window's view.
+    pub zircon_view: u32,
+}
+
+impl FuchsiaHandle {
+    pub fn empty() -> FuchsiaHandle {
+        FuchsiaHandle { zircon_view: 0 }
+    }
+}
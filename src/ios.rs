@@ -0,0 +1,31 @@
+use core::ptr;
+
+/// Raw window handle for iOS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct IOSHandle {
+    pub ui_window: *mut core::ffi::c_void,
+    pub ui_view: *mut core::ffi::c_void,
+    pub ui_view_controller: *mut core::ffi::c_void,
+}
+
+impl IOSHandle {
+    pub fn empty() -> IOSHandle {
+        IOSHandle {
+            ui_window: ptr::null_mut(),
+            ui_view: ptr::null_mut(),
+            ui_view_controller: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw display handle for UIKit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct UiKitDisplayHandle {}
+
+impl UiKitDisplayHandle {
+    pub fn empty() -> UiKitDisplayHandle {
+        UiKitDisplayHandle {}
+    }
+}
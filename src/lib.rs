@@ -12,49 +12,65 @@
 //! be used along with the struct update syntax to construct it. See each specific struct for
 //! examples.
 //!
+//! ## Cargo features
+//!
+//! Every platform module, and every variant of [`RawWindowHandle`]/[`RawDisplayHandle`], is
+//! normally gated to the target it's relevant on (e.g. `windows` only on `target_os = "windows"`).
+//! Enabling the matching cargo feature (`x11`, `wayland`, `windows`, `appkit`, `uikit`, `android`,
+//! `web`, `redox`, `fuchsia`) additionally exposes that platform's module and variants on *any*
+//! host, so that code that only ever constructs or marshals handles (tests, serializers,
+//! cross-process tooling) can name and build every variant from a single host.
+//!
 #![cfg_attr(feature = "nightly-docs", feature(doc_cfg))]
 #![no_std]
 
-#[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "android")))]
-#[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "android"))]
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "android", target_os = "android"))))]
+#[cfg_attr(
+    not(feature = "nightly-docs"),
+    cfg(any(feature = "android", target_os = "android"))
+)]
 pub mod android;
-#[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "ios")))]
-#[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "ios"))]
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "uikit", target_os = "ios"))))]
+#[cfg_attr(
+    not(feature = "nightly-docs"),
+    cfg(any(feature = "uikit", target_os = "ios"))
+)]
 pub mod ios;
-#[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "macos")))]
-#[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "macos"))]
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "appkit", target_os = "macos"))))]
+#[cfg_attr(
+    not(feature = "nightly-docs"),
+    cfg(any(feature = "appkit", target_os = "macos"))
+)]
 pub mod macos;
-#[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "redox")))]
-#[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "redox"))]
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "redox", target_os = "redox"))))]
+#[cfg_attr(
+    not(feature = "nightly-docs"),
+    cfg(any(feature = "redox", target_os = "redox"))
+)]
 pub mod redox;
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "fuchsia", target_os = "fuchsia"))))]
 #[cfg_attr(
-    feature = "nightly-docs",
-    doc(cfg(any(
-        target_os = "linux",
-        target_os = "dragonfly",
-        target_os = "freebsd",
-        target_os = "netbsd",
-        target_os = "openbsd",
-        target_os = "solaris"
-    )))
+    not(feature = "nightly-docs"),
+    cfg(any(feature = "fuchsia", target_os = "fuchsia"))
 )]
+pub mod fuchsia;
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "x11", feature = "wayland", free_unix))))]
 #[cfg_attr(
     not(feature = "nightly-docs"),
-    cfg(any(
-        target_os = "linux",
-        target_os = "dragonfly",
-        target_os = "freebsd",
-        target_os = "netbsd",
-        target_os = "openbsd",
-        target_os = "solaris"
-    ))
+    cfg(any(feature = "x11", feature = "wayland", free_unix))
 )]
 pub mod unix;
-#[cfg_attr(feature = "nightly-docs", doc(cfg(target_arch = "wasm32")))]
-#[cfg_attr(not(feature = "nightly-docs"), cfg(target_arch = "wasm32"))]
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "web", target_arch = "wasm32"))))]
+#[cfg_attr(
+    not(feature = "nightly-docs"),
+    cfg(any(feature = "web", target_arch = "wasm32"))
+)]
 pub mod web;
-#[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "windows")))]
-#[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "windows"))]
+#[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "windows", target_os = "windows"))))]
+#[cfg_attr(
+    not(feature = "nightly-docs"),
+    cfg(any(feature = "windows", target_os = "windows"))
+)]
 pub mod windows;
 
 mod platform {
@@ -64,14 +80,9 @@ mod platform {
     pub use crate::macos::*;
     #[cfg(target_os = "redox")]
     pub use crate::redox::*;
-    #[cfg(any(
-        target_os = "linux",
-        target_os = "dragonfly",
-        target_os = "freebsd",
-        target_os = "netbsd",
-        target_os = "openbsd",
-        target_os = "solaris",
-    ))]
+    #[cfg(target_os = "fuchsia")]
+    pub use crate::fuchsia::*;
+    #[cfg(free_unix)]
     pub use crate::unix::*;
     #[cfg(target_os = "windows")]
     pub use crate::windows::*;
@@ -103,102 +114,72 @@ pub unsafe trait HasRawWindowHandle {
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RawWindowHandle {
-    #[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "ios")))]
-    #[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "ios"))]
-    IOS(ios::IOSHandle),
-
-    #[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "macos")))]
-    #[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "macos"))]
-    MacOS(macos::MacOSHandle),
-
-    #[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "redox")))]
-    #[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "redox"))]
-    Redox(redox::RedoxHandle),
-
-    #[cfg_attr(
-        feature = "nightly-docs",
-        doc(cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd",
-            target_os = "solaris"
-        )))
-    )]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "uikit", target_os = "ios"))))]
     #[cfg_attr(
         not(feature = "nightly-docs"),
-        cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd",
-            target_os = "solaris"
-        ))
+        cfg(any(feature = "uikit", target_os = "ios"))
     )]
-    Xlib(unix::XlibHandle),
+    IOS(ios::IOSHandle),
 
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "appkit", target_os = "macos"))))]
     #[cfg_attr(
-        feature = "nightly-docs",
-        doc(cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd",
-            target_os = "solaris"
-        )))
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "appkit", target_os = "macos"))
     )]
+    MacOS(macos::MacOSHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "redox", target_os = "redox"))))]
     #[cfg_attr(
         not(feature = "nightly-docs"),
-        cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd",
-            target_os = "solaris"
-        ))
+        cfg(any(feature = "redox", target_os = "redox"))
     )]
+    Redox(redox::RedoxHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "x11", free_unix))))]
+    #[cfg_attr(not(feature = "nightly-docs"), cfg(any(feature = "x11", free_unix)))]
+    Xlib(unix::XlibHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "x11", free_unix))))]
+    #[cfg_attr(not(feature = "nightly-docs"), cfg(any(feature = "x11", free_unix)))]
     Xcb(unix::XcbHandle),
 
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "wayland", free_unix))))]
+    #[cfg_attr(not(feature = "nightly-docs"), cfg(any(feature = "wayland", free_unix)))]
+    Wayland(unix::WaylandHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "fuchsia", target_os = "fuchsia"))))]
     #[cfg_attr(
-        feature = "nightly-docs",
-        doc(cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd"
-        )))
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "fuchsia", target_os = "fuchsia"))
     )]
+    Fuchsia(fuchsia::FuchsiaHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "windows", target_os = "windows"))))]
     #[cfg_attr(
         not(feature = "nightly-docs"),
-        cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd"
-        ))
+        cfg(any(feature = "windows", target_os = "windows"))
     )]
-    Wayland(unix::WaylandHandle),
-
-    #[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "windows")))]
-    #[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "windows"))]
     Windows(windows::WindowsHandle),
 
-    #[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "windows")))]
-    #[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "windows"))]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "windows", target_os = "windows"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "windows", target_os = "windows"))
+    )]
     WinRT(windows::WinRTHandle),
 
-    #[cfg_attr(feature = "nightly-docs", doc(cfg(target_arch = "wasm32")))]
-    #[cfg_attr(not(feature = "nightly-docs"), cfg(target_arch = "wasm32"))]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "web", target_arch = "wasm32"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "web", target_arch = "wasm32"))
+    )]
     Web(web::WebHandle),
 
-    #[cfg_attr(feature = "nightly-docs", doc(cfg(target_os = "android")))]
-    #[cfg_attr(not(feature = "nightly-docs"), cfg(target_os = "android"))]
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "android", target_os = "android"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "android", target_os = "android"))
+    )]
     Android(android::AndroidHandle),
 }
 
@@ -229,3 +210,100 @@ unsafe impl HasRawWindowHandle for TrustedWindowHandle {
         self.raw
     }
 }
+
+/// Display server connection that wraps around a raw display handle.
+///
+/// # Safety guarantees
+///
+/// Users can safely assume that non-`null`/`0` fields are valid handles, and it is up to the
+/// implementer of this trait to ensure that condition is upheld.
+///
+/// A display handle is independent of any particular window and may be shared by many windows'
+/// [`RawWindowHandle`]s; graphics APIs that create a connection or device once and then create
+/// surfaces for multiple windows (e.g. Vulkan, EGL) should use this instead of digging a display
+/// out of a specific window handle.
+///
+/// The exact handle returned by `raw_display_handle` must remain consistent between multiple
+/// calls to `raw_display_handle` as long as not indicated otherwise by platform specific events.
+pub unsafe trait HasRawDisplayHandle {
+    fn raw_display_handle(&self) -> RawDisplayHandle;
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RawDisplayHandle {
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "uikit", target_os = "ios"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "uikit", target_os = "ios"))
+    )]
+    UiKit(ios::UiKitDisplayHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "appkit", target_os = "macos"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "appkit", target_os = "macos"))
+    )]
+    AppKit(macos::AppKitDisplayHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "x11", free_unix))))]
+    #[cfg_attr(not(feature = "nightly-docs"), cfg(any(feature = "x11", free_unix)))]
+    Xlib(unix::XlibDisplayHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "x11", free_unix))))]
+    #[cfg_attr(not(feature = "nightly-docs"), cfg(any(feature = "x11", free_unix)))]
+    Xcb(unix::XcbDisplayHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "wayland", free_unix))))]
+    #[cfg_attr(not(feature = "nightly-docs"), cfg(any(feature = "wayland", free_unix)))]
+    Wayland(unix::WaylandDisplayHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "windows", target_os = "windows"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "windows", target_os = "windows"))
+    )]
+    Windows(windows::WindowsDisplayHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "web", target_arch = "wasm32"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "web", target_arch = "wasm32"))
+    )]
+    Web(web::WebDisplayHandle),
+
+    #[cfg_attr(feature = "nightly-docs", doc(cfg(any(feature = "android", target_os = "android"))))]
+    #[cfg_attr(
+        not(feature = "nightly-docs"),
+        cfg(any(feature = "android", target_os = "android"))
+    )]
+    Android(android::AndroidDisplayHandle),
+}
+
+/// This wraps a [`RawDisplayHandle`] to give it a [`HasRawDisplayHandle`] impl.
+///
+/// The `HasRawDisplayHandle` trait must be an `unsafe` trait because *other*
+/// unsafe code is going to rely on it to provide accurate display handle info.
+/// Since `RawDisplayHandle` is an enum and enum fields are public, anyone could
+/// make any random `RawDisplayHandle` value in safe code.
+///
+/// The solution is that you assert that you're trusting a particular handle
+/// value by (unsafely) placing it within this wrapper struct.
+pub struct TrustedDisplayHandle {
+    raw: RawDisplayHandle,
+}
+impl TrustedDisplayHandle {
+    /// Assert that the `RawDisplayHandle` value can be trusted.
+    ///
+    /// ## Safety
+    /// If the value violates any of the safety outlines given in the
+    /// [`HasRawDisplayHandle`] trait this can lead to UB.
+    pub const unsafe fn new(raw: RawDisplayHandle) -> Self {
+        Self { raw }
+    }
+}
+unsafe impl HasRawDisplayHandle for TrustedDisplayHandle {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.raw
+    }
+}
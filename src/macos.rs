@@ -0,0 +1,29 @@
+use core::ptr;
+
+/// Raw window handle for macOS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct MacOSHandle {
+    pub ns_window: *mut core::ffi::c_void,
+    pub ns_view: *mut core::ffi::c_void,
+}
+
+impl MacOSHandle {
+    pub fn empty() -> MacOSHandle {
+        MacOSHandle {
+            ns_window: ptr::null_mut(),
+            ns_view: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw display handle for AppKit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct AppKitDisplayHandle {}
+
+impl AppKitDisplayHandle {
+    pub fn empty() -> AppKitDisplayHandle {
+        AppKitDisplayHandle {}
+    }
+}
@@ -0,0 +1,16 @@
+use core::ptr;
+
+/// Raw window handle for Redox OS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct RedoxHandle {
+    pub window: *mut core::ffi::c_void,
+}
+
+impl RedoxHandle {
+    pub fn empty() -> RedoxHandle {
+        RedoxHandle {
+            window: ptr::null_mut(),
+        }
+    }
+}
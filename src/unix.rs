@@ -0,0 +1,112 @@
+use core::ptr;
+
+/// Raw window handle for Xlib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct XlibHandle {
+    /// An Xlib `Window`.
+    pub window: core::ffi::c_ulong,
+    /// A pointer to an Xlib `Display`.
+    pub display: *mut core::ffi::c_void,
+}
+
+impl XlibHandle {
+    pub fn empty() -> XlibHandle {
+        XlibHandle {
+            window: 0,
+            display: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw display handle for Xlib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct XlibDisplayHandle {
+    /// A pointer to an Xlib `Display`.
+    pub display: *mut core::ffi::c_void,
+    /// The screen index.
+    pub screen: core::ffi::c_int,
+}
+
+impl XlibDisplayHandle {
+    pub fn empty() -> XlibDisplayHandle {
+        XlibDisplayHandle {
+            display: ptr::null_mut(),
+            screen: 0,
+        }
+    }
+}
+
+/// Raw window handle for XCB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct XcbHandle {
+    /// An X11 `xcb_window_t`.
+    pub window: u32,
+    /// A pointer to an `xcb_connection_t`.
+    pub connection: *mut core::ffi::c_void,
+}
+
+impl XcbHandle {
+    pub fn empty() -> XcbHandle {
+        XcbHandle {
+            window: 0,
+            connection: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw display handle for XCB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct XcbDisplayHandle {
+    /// A pointer to an `xcb_connection_t`.
+    pub connection: *mut core::ffi::c_void,
+    /// The screen index.
+    pub screen: core::ffi::c_int,
+}
+
+impl XcbDisplayHandle {
+    pub fn empty() -> XcbDisplayHandle {
+        XcbDisplayHandle {
+            connection: ptr::null_mut(),
+            screen: 0,
+        }
+    }
+}
+
+/// Raw window handle for Wayland.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WaylandHandle {
+    /// A pointer to a `wl_surface`.
+    pub surface: *mut core::ffi::c_void,
+    /// A pointer to a `wl_display`.
+    pub display: *mut core::ffi::c_void,
+}
+
+impl WaylandHandle {
+    pub fn empty() -> WaylandHandle {
+        WaylandHandle {
+            surface: ptr::null_mut(),
+            display: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw display handle for Wayland.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WaylandDisplayHandle {
+    /// A pointer to a `wl_display`.
+    pub display: *mut core::ffi::c_void,
+}
+
+impl WaylandDisplayHandle {
+    pub fn empty() -> WaylandDisplayHandle {
+        WaylandDisplayHandle {
+            display: ptr::null_mut(),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+/// Raw window handle for the Web.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WebHandle {
+    /// An ID value inserted into the `data-raw-handle` attribute of the canvas element.
+    ///
+    /// The startup code will call `canvas.setAttribute('data-raw-handle', id)`, where `id` is
+    /// this value.
+    pub id: u32,
+}
+
+impl WebHandle {
+    pub fn empty() -> WebHandle {
+        WebHandle { id: 0 }
+    }
+}
+
+/// Raw display handle for the Web.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WebDisplayHandle {}
+
+impl WebDisplayHandle {
+    pub fn empty() -> WebDisplayHandle {
+        WebDisplayHandle {}
+    }
+}
@@ -0,0 +1,47 @@
+use core::ptr;
+
+/// Raw window handle for Win32.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WindowsHandle {
+    /// A Win32 `HWND` handle.
+    pub hwnd: *mut core::ffi::c_void,
+    /// The `HINSTANCE` associated with this type's `HWND`.
+    pub hinstance: *mut core::ffi::c_void,
+}
+
+impl WindowsHandle {
+    pub fn empty() -> WindowsHandle {
+        WindowsHandle {
+            hwnd: ptr::null_mut(),
+            hinstance: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw window handle for WinRT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WinRTHandle {
+    /// A WinRT `CoreWindow` handle.
+    pub core_window: *mut core::ffi::c_void,
+}
+
+impl WinRTHandle {
+    pub fn empty() -> WinRTHandle {
+        WinRTHandle {
+            core_window: ptr::null_mut(),
+        }
+    }
+}
+
+/// Raw display handle for Win32.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WindowsDisplayHandle {}
+
+impl WindowsDisplayHandle {
+    pub fn empty() -> WindowsDisplayHandle {
+        WindowsDisplayHandle {}
+    }
+}